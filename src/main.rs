@@ -1,20 +1,87 @@
 use std::env;
 use std::fs;
+use std::io::Read;
 mod matriz_buscaminas;
 
+/// Formato de salida con el que se renderiza el tablero resuelto.
+#[derive(Debug, PartialEq)]
+enum FormatoSalida {
+    Ascii,
+    Json,
+    Base64,
+    Dot,
+}
+
+impl FormatoSalida {
+    fn desde_str(valor: &str) -> Result<FormatoSalida, String> {
+        match valor {
+            "ascii" => Ok(FormatoSalida::Ascii),
+            "json" => Ok(FormatoSalida::Json),
+            "base64" => Ok(FormatoSalida::Base64),
+            "dot" => Ok(FormatoSalida::Dot),
+            otro => Err(format!("Formato desconocido: {}", otro)),
+        }
+    }
+}
+
+/// Configuración resultante de parsear los argumentos de linea de comandos.
+struct Config {
+    desde_stdin: bool,
+    archivo_entrada: Option<String>,
+    archivo_salida: Option<String>,
+    formato: FormatoSalida,
+    formato_tablero: Option<matriz_buscaminas::FormatoTablero>,
+    jugar: bool,
+    entrada_base64: bool,
+}
+
+const USO: &str = "Uso: buscaminas [opciones] <archivo>\n\
+\n\
+Opciones:\n\
+  -o, --output <archivo>   Escribe el tablero resuelto en <archivo> en lugar de stdout\n\
+  --stdin                  Lee el tablero desde la entrada estandar\n\
+  --format <formato>       Formato de salida: ascii (por defecto), json, base64 o dot\n\
+  --play                   Juega una partida interactiva por consola en lugar de resolver e imprimir\n\
+  --from-base64            Interpreta la entrada como un tablero codificado con to_base64\n\
+  --mine-char <caracter>   Caracter que representa una mina al parsear la entrada (por defecto '*')\n\
+  --empty-chars <chars>    Caracteres que representan celdas vacias al parsear, separados por comas (por defecto '·')\n\
+  --mine-glyph <caracter>  Glifo de mina en la salida (por defecto el valor de --mine-char)\n\
+  --empty-glyph <caracter> Glifo de celda vacia en la salida (por defecto el primero de --empty-chars)\n\
+\n\
+<archivo> puede omitirse si se usa --stdin, o reemplazarse por \"-\" para leer de stdin.";
+
 fn main() {
-    let result_tablero_string = abrir_archivo();
-    let tablero_string = match result_tablero_string {
-        Ok(contenido_archivo) => contenido_archivo,
+    let args: Vec<String> = env::args().collect();
+    let config = match parsear_argumentos(&args) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{}", error);
+            eprintln!("{}", USO);
+            return;
+        }
+    };
+
+    let tablero_bytes = match leer_entrada(&config) {
+        Ok(bytes) => bytes,
         Err(error) => {
             print!("No se pudo abrir el archivo: {}", error);
             return;
         }
     };
-    let tablero_bytes = tablero_string.as_bytes();
 
-    let mut tablero_matriz = matriz_buscaminas::MatrizBuscaminas::new();
-    match tablero_matriz.popular_desde_bytes(tablero_bytes) {
+    let mut tablero_matriz = match &config.formato_tablero {
+        Some(formato) => matriz_buscaminas::MatrizBuscaminas::con_formato(formato.clone()),
+        None => matriz_buscaminas::MatrizBuscaminas::new(),
+    };
+    let resultado_parseo = if config.entrada_base64 {
+        match std::str::from_utf8(&tablero_bytes) {
+            Ok(texto) => tablero_matriz.from_base64(texto),
+            Err(_) => Err("La entrada base64 no es UTF-8 valida".to_owned()),
+        }
+    } else {
+        tablero_matriz.popular_desde_bytes(&tablero_bytes)
+    };
+    match resultado_parseo {
         Ok(()) => {}
         Err(error) => {
             print!("Error al parsear archivo a tablero: {}", error);
@@ -22,36 +89,223 @@ fn main() {
         }
     }
     tablero_matriz.contar_bombas();
-    tablero_matriz.imprimir_como_buscaminas();
+
+    if config.jugar {
+        jugar(&mut tablero_matriz);
+        return;
+    }
+
+    let salida = match renderizar(&tablero_matriz, &config.formato) {
+        Ok(salida) => salida,
+        Err(error) => {
+            print!("No se pudo generar la salida: {}", error);
+            return;
+        }
+    };
+
+    match config.archivo_salida {
+        Some(archivo) => {
+            if let Err(error) = fs::write(&archivo, salida) {
+                print!("No se pudo escribir el archivo: {}", error);
+            }
+        }
+        None => print!("{}", salida),
+    }
 }
 
-/// Abre el archivo indicado como argumento al ejecutar el programa.
-/// El argumento debe ser el path al archivo incluyendo el nombre del archivo
-/// desde la carpeta raiz en la que se ejecute el programa.
+/// Renderiza una MatrizBuscaminas ya resuelta en el formato pedido.
+fn renderizar(
+    tablero: &matriz_buscaminas::MatrizBuscaminas,
+    formato: &FormatoSalida,
+) -> Result<String, String> {
+    match formato {
+        FormatoSalida::Ascii => Ok(tablero.to_ascii()),
+        FormatoSalida::Json => Ok(tablero.to_json()),
+        FormatoSalida::Base64 => Ok(tablero.to_base64()),
+        FormatoSalida::Dot => Ok(tablero.exportar_como_dot()),
+    }
+}
+
+/// Juega una partida interactiva por consola: en cada turno imprime el estado
+/// actual del tablero y lee un comando de la entrada estandar.
 ///
-/// Devuelve un Result<String, Error>, siendo el String el contenido del archivo y Error en caso de que la cantidad de argumentos de entrada no sea la correcta, o
-/// el error devuelto por fs::read_to_string si no se puede leer el archivo.
+/// Comandos soportados:
+///   r <fila> <columna>   Revela la celda indicada
+///   f <fila> <columna>   Marca/desmarca una bandera en la celda indicada
+///   q                    Termina la partida
 ///
-/// #Ejemplo
+/// La partida termina cuando el jugador revela una bomba, pide salir, o se
+/// acaba la entrada estandar.
+fn jugar(tablero: &mut matriz_buscaminas::MatrizBuscaminas) {
+    let entrada = std::io::stdin();
+    loop {
+        tablero.imprimir_estado();
+
+        let mut linea = String::new();
+        if entrada.read_line(&mut linea).unwrap_or(0) == 0 {
+            break;
+        }
+        let partes: Vec<&str> = linea.split_whitespace().collect();
+        match partes.as_slice() {
+            ["q"] => break,
+            ["r", fila, columna] | ["f", fila, columna] => {
+                let (fila, columna) = match (fila.parse::<i32>(), columna.parse::<i32>()) {
+                    (Ok(fila), Ok(columna)) => (fila, columna),
+                    _ => {
+                        println!("Coordenadas invalidas");
+                        continue;
+                    }
+                };
+                if partes[0] == "r" {
+                    match tablero.revelar(fila, columna) {
+                        matriz_buscaminas::ResultadoJugada::Boom => {
+                            tablero.imprimir_estado();
+                            println!("Boom! Perdiste.");
+                            break;
+                        }
+                        matriz_buscaminas::ResultadoJugada::Revelada => {}
+                        matriz_buscaminas::ResultadoJugada::FueraDeRango => {
+                            println!("Coordenadas fuera de rango")
+                        }
+                    }
+                } else {
+                    tablero.alternar_bandera(fila, columna);
+                }
+            }
+            _ => println!("Comando desconocido. Usá \"r fila columna\", \"f fila columna\" o \"q\"."),
+        }
+    }
+}
+
+/// Obtiene los bytes del tablero a partir de la configuración: desde stdin o
+/// desde el archivo de entrada indicado.
+fn leer_entrada(config: &Config) -> Result<Vec<u8>, std::io::Error> {
+    if config.desde_stdin {
+        let mut buffer = Vec::new();
+        std::io::stdin().read_to_end(&mut buffer)?;
+        return Ok(buffer);
+    }
+    let archivo = config.archivo_entrada.as_ref().expect(
+        "archivo_entrada debe estar presente cuando desde_stdin es false",
+    );
+    fs::read(archivo)
+}
+
+/// Parsea un único caracter recibido como argumento de linea de comandos,
+/// fallando si el string esta vacio o tiene mas de un caracter UTF-8.
+fn parsear_caracter(valor: &str, nombre_bandera: &str) -> Result<char, String> {
+    let mut caracteres = valor.chars();
+    let caracter = caracteres
+        .next()
+        .ok_or_else(|| format!("{} requiere un unico caracter", nombre_bandera))?;
+    if caracteres.next().is_some() {
+        return Err(format!("{} requiere un unico caracter", nombre_bandera));
+    }
+    Ok(caracter)
+}
+
+/// Parsea los argumentos de linea de comandos al estilo getopts: separa las
+/// banderas (`-o`/`--output`, `--stdin`, `--format`, `--mine-char`/`--empty-chars`/
+/// `--mine-glyph`/`--empty-glyph`) del operando posicional (la ruta al archivo
+/// de entrada, o "-" para leer de stdin).
 ///
-/// ```
-/// let contenido_result = abrir_archivo();
-/// let contenido = match contenido_result {
-///     Ok(contenido_archivo) => contenido_archivo,
-///     Err(error) => {
-///         print!("No se pudo abrir el archivo: {}", error);
-///         return;
-///     }
-/// };
-/// ```
-fn abrir_archivo() -> Result<String, std::io::Error> {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            "Cantidad de argumentos invalida",
-        ));
-    }
-    let archivo = &args[1];
-    fs::read_to_string(archivo)
+/// Devuelve un Config listo para usar en main, o un mensaje de error si falta
+/// el operando, se repite, o se encuentra una bandera desconocida.
+fn parsear_argumentos(args: &[String]) -> Result<Config, String> {
+    let mut desde_stdin = false;
+    let mut archivo_entrada: Option<String> = None;
+    let mut archivo_salida: Option<String> = None;
+    let mut formato = FormatoSalida::Ascii;
+    let mut jugar = false;
+    let mut entrada_base64 = false;
+    let mut mine_char: Option<char> = None;
+    let mut empty_chars: Option<Vec<char>> = None;
+    let mut mine_glyph: Option<char> = None;
+    let mut empty_glyph: Option<char> = None;
+
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-" | "--stdin" => desde_stdin = true,
+            "--play" => jugar = true,
+            "--from-base64" => entrada_base64 = true,
+            "-o" | "--output" => {
+                let valor = iter
+                    .next()
+                    .ok_or_else(|| "Falta el argumento de -o/--output".to_owned())?;
+                archivo_salida = Some(valor.clone());
+            }
+            "--format" => {
+                let valor = iter
+                    .next()
+                    .ok_or_else(|| "Falta el argumento de --format".to_owned())?;
+                formato = FormatoSalida::desde_str(valor)?;
+            }
+            "--mine-char" => {
+                let valor = iter
+                    .next()
+                    .ok_or_else(|| "Falta el argumento de --mine-char".to_owned())?;
+                mine_char = Some(parsear_caracter(valor, "--mine-char")?);
+            }
+            "--empty-chars" => {
+                let valor = iter
+                    .next()
+                    .ok_or_else(|| "Falta el argumento de --empty-chars".to_owned())?;
+                let caracteres: Option<Vec<char>> =
+                    valor.split(',').map(|c| c.chars().next()).collect();
+                empty_chars = Some(caracteres.ok_or_else(|| {
+                    "--empty-chars requiere caracteres separados por comas".to_owned()
+                })?);
+            }
+            "--mine-glyph" => {
+                let valor = iter
+                    .next()
+                    .ok_or_else(|| "Falta el argumento de --mine-glyph".to_owned())?;
+                mine_glyph = Some(parsear_caracter(valor, "--mine-glyph")?);
+            }
+            "--empty-glyph" => {
+                let valor = iter
+                    .next()
+                    .ok_or_else(|| "Falta el argumento de --empty-glyph".to_owned())?;
+                empty_glyph = Some(parsear_caracter(valor, "--empty-glyph")?);
+            }
+            posicional if !posicional.starts_with('-') => {
+                if archivo_entrada.is_some() {
+                    return Err(format!("Argumento posicional inesperado: {}", posicional));
+                }
+                archivo_entrada = Some(posicional.to_owned());
+            }
+            desconocido => return Err(format!("Opción desconocida: {}", desconocido)),
+        }
+    }
+
+    if !desde_stdin && archivo_entrada.is_none() {
+        return Err("Debe indicarse un archivo de entrada o --stdin".to_owned());
+    }
+
+    let formato_tablero = if mine_char.is_some()
+        || empty_chars.is_some()
+        || mine_glyph.is_some()
+        || empty_glyph.is_some()
+    {
+        let mina = mine_char.unwrap_or('*');
+        let vacios = empty_chars.unwrap_or_else(|| vec!['·']);
+        let glifo_mina = mine_glyph.unwrap_or(mina);
+        let glifo_vacia = empty_glyph.unwrap_or_else(|| *vacios.first().unwrap_or(&'·'));
+        Some(matriz_buscaminas::FormatoTablero::nuevo(
+            mina, vacios, glifo_mina, glifo_vacia,
+        ))
+    } else {
+        None
+    };
+
+    Ok(Config {
+        desde_stdin,
+        archivo_entrada,
+        archivo_salida,
+        formato,
+        formato_tablero,
+        jugar,
+        entrada_base64,
+    })
 }
@@ -2,59 +2,151 @@
 /// como tablero del juego buscaminas.
 pub struct MatrizBuscaminas {
     valores: Vec<i32>,
+    estado: Vec<EstadoCelda>,
     columnas: i32,
     filas: i32,
+    formato: FormatoTablero,
 }
 
-/// Constante que representa el valor del byte del caracter '*' en ASCII
-const ASTERISCO_BYTE: u8 = b'*';
+/// Configura los caracteres que `popular_desde_bytes` acepta como mina y como
+/// celda vacia al parsear un tablero, y los glifos que se usan al imprimirlo.
+/// `por_defecto` preserva el comportamiento historico ('*' para minas y '·'
+/// para celdas vacias), mientras que `nuevo` permite boards con otro charset,
+/// por ejemplo '#' para minas o '-'/'0' para celdas vacias.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatoTablero {
+    caracter_mina: char,
+    caracteres_vacios: Vec<char>,
+    glifo_mina: char,
+    glifo_vacia: char,
+}
 
-/// Constantes que representan el valor del byte del caracter '·' en ASCII
-const INTERDOT_FIRST_BYTE: u8 = b'\xC2';
-const INTERDOT_SECOND_BYTE: u8 = b'\xB7';
-const DOT_BYTE: u8 = b'.';
+impl FormatoTablero {
+    /// Formato por defecto: '*' para minas y '·' para celdas vacias, tanto en
+    /// la entrada como en la salida.
+    pub fn por_defecto() -> FormatoTablero {
+        FormatoTablero {
+            caracter_mina: '*',
+            caracteres_vacios: vec!['·'],
+            glifo_mina: '*',
+            glifo_vacia: '·',
+        }
+    }
+
+    /// Arma un FormatoTablero con el caracter de mina y los caracteres de
+    /// celda vacia aceptados en la entrada, y los glifos usados en la salida.
+    pub fn nuevo(
+        caracter_mina: char,
+        caracteres_vacios: Vec<char>,
+        glifo_mina: char,
+        glifo_vacia: char,
+    ) -> FormatoTablero {
+        FormatoTablero {
+            caracter_mina,
+            caracteres_vacios,
+            glifo_mina,
+            glifo_vacia,
+        }
+    }
+}
+
+impl Default for FormatoTablero {
+    fn default() -> Self {
+        Self::por_defecto()
+    }
+}
+
+/// Representa el estado de visibilidad de una celda desde la perspectiva del jugador.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EstadoCelda {
+    Oculta,
+    Revelada,
+    Bandera,
+}
+
+/// Resultado de intentar revelar una celda con `revelar`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResultadoJugada {
+    /// La celda revelada era una bomba: fin del juego.
+    Boom,
+    /// La celda (y, en caso de ser cero, su región conexa) quedó revelada.
+    Revelada,
+    /// Las coordenadas están fuera del tablero.
+    FueraDeRango,
+}
+
+/// Alfabeto estandar de base64 (RFC 4648) usado por to_base64/from_base64.
+const ALFABETO_BASE64: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Desplazamientos (fila, columna) hacia las ocho celdas adyacentes a una
+/// celda dada, usados tanto para contar bombas como para el flood-fill de revelar.
+const OFFSETS_ADYACENTES: [(i32, i32); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
 
 impl MatrizBuscaminas {
-    /// Devuelve una MatrizBuscaminas vacia.
+    /// Devuelve una MatrizBuscaminas vacia con el FormatoTablero por defecto.
     pub fn new() -> MatrizBuscaminas {
+        Self::con_formato(FormatoTablero::por_defecto())
+    }
+
+    /// Devuelve una MatrizBuscaminas vacia que parseará e imprimirá tableros
+    /// usando el FormatoTablero indicado en lugar del formato por defecto.
+    pub fn con_formato(formato: FormatoTablero) -> MatrizBuscaminas {
         MatrizBuscaminas {
             columnas: 0,
             filas: 0,
             valores: vec![0; 0],
+            estado: vec![EstadoCelda::Oculta; 0],
+            formato,
         }
     }
 
     /// Metodo público que permite llenar una matriz vacia a partir de un arreglo
-    /// de u8 compuesto por '*' representando bombas y '·' representando celdas vacias.
+    /// de bytes UTF-8, usando el FormatoTablero de la matriz para reconocer el
+    /// caracter de mina y los caracteres de celda vacia (por defecto, '*' y '·').
     /// Tambien genera una fila por cada salto de linea.
     ///
     /// # Argumentos
     ///
-    /// * `bytes` - Arreglo de u8 con los caracteres que representan un juego de buscaminas.
+    /// * `bytes` - Arreglo de bytes UTF-8 con los caracteres que representan un juego de buscaminas.
     ///
     /// #Ejemplo
     ///
     /// ```
-    /// let bytes = [b'*', b'.', b'*' , b'.', b'\n']
+    /// let bytes = "*·*·".as_bytes();
     /// let mut matriz = MatrizBuscaminas::new();
     /// matriz.popular_desde_bytes(bytes);
     /// ```
     pub fn popular_desde_bytes(&mut self, bytes: &[u8]) -> Result<(), String> {
-        self.filas = Self::contar_filas(bytes);
-        self.columnas = Self::contar_columnas(bytes);
-        if !Self::validar_mapa(bytes, self.columnas) {
-            return Err(
-                "Mapa invalido, debe ser cuadrado o rectangular y estar compuesto por “·” o “*”"
-                    .to_owned(),
-            );
+        let texto =
+            std::str::from_utf8(bytes).map_err(|_| "El tablero no es UTF-8 valido".to_owned())?;
+
+        self.filas = Self::contar_filas(texto);
+        self.columnas = Self::contar_columnas(texto, &self.formato);
+        if !Self::validar_mapa(texto, self.columnas, &self.formato) {
+            return Err(format!(
+                "Mapa invalido, debe ser cuadrado o rectangular y estar compuesto por “{}” o “{}”",
+                self.formato.caracteres_vacios.iter().collect::<String>(),
+                self.formato.caracter_mina
+            ));
         }
-        for byte in bytes {
-            if *byte == ASTERISCO_BYTE {
+        for c in texto.chars() {
+            if c == self.formato.caracter_mina {
                 self.valores.push(-1)
-            } else if *byte == INTERDOT_FIRST_BYTE || *byte == DOT_BYTE {
+            } else if self.formato.caracteres_vacios.contains(&c) {
                 self.valores.push(0)
             }
         }
+        self.estado = vec![EstadoCelda::Oculta; self.valores.len()];
         Ok(())
     }
 
@@ -74,66 +166,85 @@ impl MatrizBuscaminas {
         }
     }
 
-    /// Metodo público que permite imprimir por salida estandar una MatrizBuscaminas
-    /// con el formato del tablero de buscaminas.
+    /// Metodo público que arma la representación en texto plano de una
+    /// MatrizBuscaminas, con el formato clasico del tablero de buscaminas
+    /// (una fila por linea, bombas y celdas vacias segun el FormatoTablero).
     ///
     /// #Ejemplo
     ///
     /// ```
-    /// let bytes = [b'*', b'.', b'*' , b'.', b'\n']
-    /// let mut matriz = MatrizBuscaminas::new();
-    /// matriz.popular_desde_bytes(&bytes);
-    /// matriz.contar_bombas();
-    /// matriz.imprimir_como_buscaminas();
+    /// let texto = matriz.to_ascii();
+    /// print!("{}", texto);
     /// ```
-    /// #Salida
-    /// *2*1
-
-    pub fn imprimir_como_buscaminas(&self) {
+    pub fn to_ascii(&self) -> String {
+        let mut salida = String::new();
         let mut contador_columnas = 0;
         for valor in &self.valores {
             if contador_columnas == self.columnas {
-                println!();
+                salida.push('\n');
                 contador_columnas = 0;
             }
             if *valor == -1 {
-                print!("*");
+                salida.push(self.formato.glifo_mina);
             } else if *valor == 0 {
-                print!("·");
+                salida.push(self.formato.glifo_vacia);
             } else {
-                print!("{}", *valor);
+                salida.push_str(&valor.to_string());
             }
             contador_columnas += 1;
         }
-        println!();
+        salida.push('\n');
+        salida
+    }
+
+    /// Metodo público que serializa una MatrizBuscaminas como un objeto JSON
+    /// con las dimensiones del tablero y sus valores resueltos.
+    ///
+    /// #Ejemplo
+    ///
+    /// ```
+    /// let json = matriz.to_json();
+    /// ```
+    pub fn to_json(&self) -> String {
+        let valores: Vec<String> = self.valores.iter().map(|v| v.to_string()).collect();
+        format!(
+            "{{\"filas\":{},\"columnas\":{},\"valores\":[{}]}}",
+            self.filas,
+            self.columnas,
+            valores.join(",")
+        )
     }
 
     /// Función interna del módulo.
-    /// Cuenta la cantidad de columnas de un arreglo de u8 a partir de la
-    /// cantidad de caracteres hasta el primer salto de linea.
-    fn contar_columnas(bytes: &[u8]) -> i32 {
+    /// Cuenta la cantidad de columnas de un string a partir de la cantidad de
+    /// caracteres hasta el primer salto de linea, según el FormatoTablero dado.
+    fn contar_columnas(texto: &str, formato: &FormatoTablero) -> i32 {
         let mut columnas = 0;
-        for byte in bytes {
-            if *byte == (b'\n') {
+        for c in texto.chars() {
+            if c == '\n' {
                 break;
-            } else if *byte == INTERDOT_FIRST_BYTE || *byte == ASTERISCO_BYTE || *byte == DOT_BYTE {
+            } else if c == formato.caracter_mina || formato.caracteres_vacios.contains(&c) {
                 columnas += 1;
             }
         }
         columnas
     }
 
-    fn validar_mapa(bytes: &[u8], columnas: i32) -> bool {
+    /// Función interna del módulo.
+    /// Valida que el string represente un mapa rectangular compuesto
+    /// unicamente por el caracter de mina y los caracteres vacios del
+    /// FormatoTablero dado (además de saltos de linea y retornos de carro).
+    fn validar_mapa(texto: &str, columnas: i32, formato: &FormatoTablero) -> bool {
         let mut contador = 0;
-        for byte in bytes {
-            if *byte == (b'\n') {
+        for c in texto.chars() {
+            if c == '\n' {
                 if contador != columnas {
                     return false;
                 }
                 contador = 0;
-            } else if *byte == INTERDOT_FIRST_BYTE || *byte == ASTERISCO_BYTE || *byte == DOT_BYTE {
+            } else if c == formato.caracter_mina || formato.caracteres_vacios.contains(&c) {
                 contador += 1;
-            } else if *byte == INTERDOT_SECOND_BYTE || *byte == (b'\r') {
+            } else if c == '\r' {
                 continue;
             } else {
                 return false;
@@ -146,18 +257,18 @@ impl MatrizBuscaminas {
     }
 
     /// Función interna del módulo.
-    /// Cuenta la cantidad de filas de un arreglo de u8 a partir de la
+    /// Cuenta la cantidad de filas de un string a partir de la
     /// cantidad de saltos de linea.
-    fn contar_filas(bytes: &[u8]) -> i32 {
+    fn contar_filas(texto: &str) -> i32 {
         let mut filas = 0;
+        let caracteres: Vec<char> = texto.chars().collect();
         let mut contador = 1;
-        for byte in bytes {
-            if *byte == (b'\n') {
+        for c in &caracteres {
+            if *c == '\n' {
                 filas += 1;
             }
-            if contador == bytes.len() && *byte != (b'\n') {
+            if contador == caracteres.len() && *c != '\n' {
                 filas += 1;
-                [bytes, &[b'\n']].concat();
             }
             contador += 1;
         }
@@ -169,18 +280,8 @@ impl MatrizBuscaminas {
     /// indice en el vector valores de la MatrizBuscaminas.
     fn aumentar_adyacentes(&mut self, i: i32) {
         let coord = Self::obtener_coordenadas(i, self.columnas);
-        let celdas_a_aumentar = [
-            (coord.0 - 1, coord.1 - 1),
-            (coord.0 - 1, coord.1),
-            (coord.0 - 1, coord.1 + 1),
-            (coord.0, coord.1 - 1),
-            (coord.0, coord.1 + 1),
-            (coord.0 + 1, coord.1 - 1),
-            (coord.0 + 1, coord.1),
-            (coord.0 + 1, coord.1 + 1),
-        ];
-        for celda in celdas_a_aumentar {
-            Self::aumentar_celda(self, celda);
+        for (df, dc) in OFFSETS_ADYACENTES {
+            Self::aumentar_celda(self, (coord.0 + df, coord.1 + dc));
         }
     }
 
@@ -197,6 +298,111 @@ impl MatrizBuscaminas {
         }
     }
 
+    /// Metodo público que revela la celda en (fila, columna) para manejar un turno
+    /// del jugador. Si la celda es una bomba el juego termina (`Boom`); si es una
+    /// celda numerada se revela unicamente esa celda; si es una celda en cero se
+    /// hace flood-fill iterativo de la región conexa de ceros y su borde numerado,
+    /// sin cruzar nunca una bomba ni reencolar celdas ya reveladas.
+    ///
+    /// #Ejemplo
+    ///
+    /// ```
+    /// match matriz.revelar(0, 0) {
+    ///     ResultadoJugada::Boom => println!("Perdiste"),
+    ///     ResultadoJugada::Revelada => matriz.imprimir_estado(),
+    ///     ResultadoJugada::FueraDeRango => println!("Coordenadas invalidas"),
+    /// }
+    /// ```
+    pub fn revelar(&mut self, fila: i32, columna: i32) -> ResultadoJugada {
+        if fila < 0 || fila >= self.filas || columna < 0 || columna >= self.columnas {
+            return ResultadoJugada::FueraDeRango;
+        }
+
+        let indice = (fila * self.columnas + columna) as usize;
+        if self.valores[indice] == -1 {
+            self.estado[indice] = EstadoCelda::Revelada;
+            return ResultadoJugada::Boom;
+        }
+        if self.valores[indice] != 0 {
+            self.estado[indice] = EstadoCelda::Revelada;
+            return ResultadoJugada::Revelada;
+        }
+
+        let mut pila = vec![(fila, columna)];
+        while let Some((f, c)) = pila.pop() {
+            let idx = (f * self.columnas + c) as usize;
+            if self.estado[idx] == EstadoCelda::Revelada {
+                continue;
+            }
+            self.estado[idx] = EstadoCelda::Revelada;
+
+            if self.valores[idx] == 0 {
+                for (df, dc) in OFFSETS_ADYACENTES {
+                    let (nf, nc) = (f + df, c + dc);
+                    if nf < 0 || nf >= self.filas || nc < 0 || nc >= self.columnas {
+                        continue;
+                    }
+                    let nidx = (nf * self.columnas + nc) as usize;
+                    if self.valores[nidx] == -1 || self.estado[nidx] == EstadoCelda::Revelada {
+                        continue;
+                    }
+                    pila.push((nf, nc));
+                }
+            }
+        }
+        ResultadoJugada::Revelada
+    }
+
+    /// Metodo público que alterna la bandera de una celda oculta, para marcarla
+    /// como sospechosa de tener una bomba sin revelarla. No tiene efecto sobre
+    /// celdas ya reveladas. Devuelve `false` si las coordenadas están fuera de rango.
+    ///
+    /// #Ejemplo
+    ///
+    /// ```
+    /// matriz.alternar_bandera(0, 0);
+    /// ```
+    pub fn alternar_bandera(&mut self, fila: i32, columna: i32) -> bool {
+        if fila < 0 || fila >= self.filas || columna < 0 || columna >= self.columnas {
+            return false;
+        }
+        let indice = (fila * self.columnas + columna) as usize;
+        self.estado[indice] = match self.estado[indice] {
+            EstadoCelda::Oculta => EstadoCelda::Bandera,
+            EstadoCelda::Bandera => EstadoCelda::Oculta,
+            revelada => revelada,
+        };
+        true
+    }
+
+    /// Metodo público que permite imprimir por salida estandar el estado actual
+    /// de una partida: celdas reveladas con su valor, una bandera para las celdas
+    /// marcadas y `?` para las celdas aún ocultas.
+    ///
+    /// #Ejemplo
+    ///
+    /// ```
+    /// matriz.imprimir_estado();
+    /// ```
+    pub fn imprimir_estado(&self) {
+        let mut contador_columnas = 0;
+        for (indice, valor) in self.valores.iter().enumerate() {
+            if contador_columnas == self.columnas {
+                println!();
+                contador_columnas = 0;
+            }
+            match self.estado[indice] {
+                EstadoCelda::Oculta => print!("?"),
+                EstadoCelda::Bandera => print!("⚑"),
+                EstadoCelda::Revelada if *valor == -1 => print!("{}", self.formato.glifo_mina),
+                EstadoCelda::Revelada if *valor == 0 => print!("{}", self.formato.glifo_vacia),
+                EstadoCelda::Revelada => print!("{}", *valor),
+            }
+            contador_columnas += 1;
+        }
+        println!();
+    }
+
     /// Función interna del módulo.
     /// Devuelve las coordenadas que tendria una celda en una representacion matricial
     /// a partir del indice en el vector valores de la MatrizBuscaminas.
@@ -205,6 +411,216 @@ impl MatrizBuscaminas {
         let columna = i % col;
         (fila, columna)
     }
+
+    /// Metodo público que codifica la MatrizBuscaminas como un string base64
+    /// compacto, pensado para transportarla en una URL o fixture de test.
+    ///
+    /// El payload es un encabezado binario de `filas` y `columnas` (dos u32
+    /// little-endian) seguido de un bit por celda (1 = bomba, 0 = vacia),
+    /// empaquetado MSB-first, todo codificado en base64 estandar.
+    ///
+    /// #Ejemplo
+    ///
+    /// ```
+    /// let codificado = matriz.to_base64();
+    /// ```
+    pub fn to_base64(&self) -> String {
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&(self.filas as u32).to_le_bytes());
+        payload.extend_from_slice(&(self.columnas as u32).to_le_bytes());
+
+        let mut byte_actual = 0u8;
+        let mut bits_en_byte = 0u8;
+        for valor in &self.valores {
+            let bit = if *valor == -1 { 1 } else { 0 };
+            byte_actual = (byte_actual << 1) | bit;
+            bits_en_byte += 1;
+            if bits_en_byte == 8 {
+                payload.push(byte_actual);
+                byte_actual = 0;
+                bits_en_byte = 0;
+            }
+        }
+        if bits_en_byte > 0 {
+            byte_actual <<= 8 - bits_en_byte;
+            payload.push(byte_actual);
+        }
+
+        Self::codificar_base64(&payload)
+    }
+
+    /// Metodo público que complementa a popular_desde_bytes: repuebla la
+    /// MatrizBuscaminas a partir de un string generado por to_base64.
+    ///
+    /// Deja `valores` con -1/0 según el bit de cada celda, listo para que el
+    /// llamador ejecute contar_bombas.
+    ///
+    /// #Ejemplo
+    ///
+    /// ```
+    /// let mut matriz = MatrizBuscaminas::new();
+    /// matriz.from_base64(&codificado)?;
+    /// matriz.contar_bombas();
+    /// ```
+    // El nombre espeja a popular_desde_bytes (repuebla una MatrizBuscaminas
+    // existente en lugar de construir una nueva), por eso toma &mut self en
+    // vez de devolver Self como esperaría la convención from_*.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_base64(&mut self, s: &str) -> Result<(), String> {
+        let payload = Self::decodificar_base64(s)?;
+        if payload.len() < 8 {
+            return Err("Base64 invalido: encabezado incompleto".to_owned());
+        }
+
+        let filas = u32::from_le_bytes([payload[0], payload[1], payload[2], payload[3]]);
+        let columnas = u32::from_le_bytes([payload[4], payload[5], payload[6], payload[7]]);
+        let celdas = (filas as usize) * (columnas as usize);
+
+        let datos = &payload[8..];
+        if datos.len() * 8 < celdas {
+            return Err(
+                "Base64 invalido: no hay suficientes bits para las dimensiones declaradas"
+                    .to_owned(),
+            );
+        }
+
+        let mut valores = Vec::with_capacity(celdas);
+        for i in 0..celdas {
+            let byte = datos[i / 8];
+            let bit = (byte >> (7 - (i % 8))) & 1;
+            valores.push(if bit == 1 { -1 } else { 0 });
+        }
+
+        self.filas = filas as i32;
+        self.columnas = columnas as i32;
+        self.estado = vec![EstadoCelda::Oculta; valores.len()];
+        self.valores = valores;
+        Ok(())
+    }
+
+    /// Metodo público que exporta la MatrizBuscaminas resuelta como un grafo
+    /// Graphviz DOT: cada celda es un nodo cuadrado ubicado en una grilla,
+    /// las bombas se renderizan en rojo con la etiqueta "*", las celdas
+    /// numeradas llevan su cantidad de bombas adyacentes como etiqueta y las
+    /// celdas en cero se muestran en gris claro. Cada fila se agrupa con
+    /// `rank=same` y se agregan aristas invisibles (entre columnas y entre
+    /// filas) para preservar la disposición 2D al renderizar con `dot`/`neato`.
+    ///
+    /// #Ejemplo
+    ///
+    /// ```
+    /// let dot = matriz.exportar_como_dot();
+    /// ```
+    pub fn exportar_como_dot(&self) -> String {
+        let mut salida =
+            String::from("digraph Buscaminas {\n    node [shape=square, style=filled, fontname=\"monospace\"];\n\n");
+
+        for fila in 0..self.filas {
+            salida.push_str("    { rank=same;\n");
+            for columna in 0..self.columnas {
+                let indice = (fila * self.columnas + columna) as usize;
+                let valor = self.valores[indice];
+                let (etiqueta, color) = match valor {
+                    -1 => ("*".to_owned(), "red"),
+                    0 => ("".to_owned(), "lightgray"),
+                    n => (n.to_string(), "white"),
+                };
+                salida.push_str(&format!(
+                    "        {} [label=\"{}\", fillcolor={}];\n",
+                    Self::nombre_nodo(fila, columna),
+                    etiqueta,
+                    color
+                ));
+            }
+            for columna in 0..self.columnas - 1 {
+                salida.push_str(&format!(
+                    "        {} -> {} [style=invis];\n",
+                    Self::nombre_nodo(fila, columna),
+                    Self::nombre_nodo(fila, columna + 1)
+                ));
+            }
+            salida.push_str("    }\n");
+        }
+
+        for fila in 0..self.filas - 1 {
+            salida.push_str(&format!(
+                "    {} -> {} [style=invis];\n",
+                Self::nombre_nodo(fila, 0),
+                Self::nombre_nodo(fila + 1, 0)
+            ));
+        }
+
+        salida.push_str("}\n");
+        salida
+    }
+
+    /// Función interna del módulo.
+    /// Devuelve el nombre de nodo DOT correspondiente a una celda dada por
+    /// sus coordenadas de fila y columna.
+    fn nombre_nodo(fila: i32, columna: i32) -> String {
+        format!("c_{}_{}", fila, columna)
+    }
+
+    /// Función interna del módulo.
+    /// Codifica un arreglo de bytes como string base64 estandar, con '=' de
+    /// relleno hasta un múltiplo de 4 caracteres de salida.
+    fn codificar_base64(bytes: &[u8]) -> String {
+        let mut salida = String::new();
+        for grupo in bytes.chunks(3) {
+            let b0 = grupo[0];
+            let b1 = *grupo.get(1).unwrap_or(&0);
+            let b2 = *grupo.get(2).unwrap_or(&0);
+
+            let indice0 = (b0 >> 2) as usize;
+            let indice1 = (((b0 & 0b11) << 4) | (b1 >> 4)) as usize;
+            let indice2 = (((b1 & 0b1111) << 2) | (b2 >> 6)) as usize;
+            let indice3 = (b2 & 0b111111) as usize;
+
+            salida.push(ALFABETO_BASE64[indice0] as char);
+            salida.push(ALFABETO_BASE64[indice1] as char);
+            salida.push(if grupo.len() > 1 {
+                ALFABETO_BASE64[indice2] as char
+            } else {
+                '='
+            });
+            salida.push(if grupo.len() > 2 {
+                ALFABETO_BASE64[indice3] as char
+            } else {
+                '='
+            });
+        }
+        salida
+    }
+
+    /// Función interna del módulo.
+    /// Decodifica un string base64 estandar a su arreglo de bytes original.
+    /// Devuelve Err si contiene caracteres fuera del alfabeto base64.
+    fn decodificar_base64(s: &str) -> Result<Vec<u8>, String> {
+        let sin_relleno = s.trim_end_matches('=');
+        let mut bits: Vec<u8> = Vec::new();
+        for c in sin_relleno.chars() {
+            let valor = ALFABETO_BASE64
+                .iter()
+                .position(|&b| b as char == c)
+                .ok_or_else(|| format!("Caracter invalido en base64: {}", c))?;
+            for desplazamiento in (0..6).rev() {
+                bits.push(((valor >> desplazamiento) & 1) as u8);
+            }
+        }
+
+        let mut bytes = Vec::with_capacity(bits.len() / 8);
+        for grupo in bits.chunks(8) {
+            if grupo.len() < 8 {
+                break;
+            }
+            let mut byte = 0u8;
+            for bit in grupo {
+                byte = (byte << 1) | bit;
+            }
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
 }
 
 #[cfg(test)]
@@ -222,8 +638,8 @@ mod tests {
     #[test]
     fn test_popular_desde_bytes_agrega_menos_uno_si_encuentra_asterisco() {
         let mut matriz = MatrizBuscaminas::new();
-        let bytes = [ASTERISCO_BYTE];
-        match matriz.popular_desde_bytes(&bytes) {
+        let bytes = "*".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
             Ok(()) => {}
             Err(error) => {
                 print!("Error al parsear archivo a tablero: {}", error);
@@ -237,8 +653,8 @@ mod tests {
     #[test]
     fn test_popular_desde_bytes_agrega_cero_si_encuentra_punto() {
         let mut matriz = MatrizBuscaminas::new();
-        let bytes = [INTERDOT_FIRST_BYTE];
-        match matriz.popular_desde_bytes(&bytes) {
+        let bytes = "·".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
             Ok(()) => {}
             Err(error) => {
                 print!("Error al parsear archivo a tablero: {}", error);
@@ -252,8 +668,8 @@ mod tests {
     #[test]
     fn test_popular_desde_bytes_agrega_fila_si_encuentra_salto_de_linea() {
         let mut matriz = MatrizBuscaminas::new();
-        let bytes = [b'\n', b'\n', b'\n'];
-        match matriz.popular_desde_bytes(&bytes) {
+        let bytes = "\n\n\n".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
             Ok(()) => {}
             Err(error) => {
                 print!("Error al parsear archivo a tablero: {}", error);
@@ -267,8 +683,8 @@ mod tests {
     #[test]
     fn test_popular_desde_bytes_agrega_columnas_si_no_encuentra_salto_de_linea() {
         let mut matriz = MatrizBuscaminas::new();
-        let bytes = [INTERDOT_FIRST_BYTE, INTERDOT_FIRST_BYTE];
-        match matriz.popular_desde_bytes(&bytes) {
+        let bytes = "··".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
             Ok(()) => {}
             Err(error) => {
                 print!("Error al parsear archivo a tablero: {}", error);
@@ -279,16 +695,52 @@ mod tests {
         assert_eq!(matriz.columnas, 2);
     }
 
+    #[test]
+    fn test_popular_desde_bytes_rechaza_caracter_fuera_del_formato() {
+        let mut matriz = MatrizBuscaminas::new();
+        let bytes = "#".as_bytes();
+        assert!(matriz.popular_desde_bytes(bytes).is_err());
+    }
+
+    #[test]
+    fn test_popular_desde_bytes_admite_formato_personalizado() {
+        let formato = FormatoTablero::nuevo('#', vec!['-', '0'], '#', '.');
+        let mut matriz = MatrizBuscaminas::con_formato(formato);
+        let bytes = "#-0\n-#-".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
+            Ok(()) => {}
+            Err(error) => {
+                print!("Error al parsear archivo a tablero: {}", error);
+                return;
+            }
+        }
+        assert_eq!(matriz.filas, 2);
+        assert_eq!(matriz.columnas, 3);
+        assert_eq!(matriz.valores, vec![-1, 0, 0, 0, -1, 0]);
+    }
+
+    #[test]
+    fn test_to_ascii_usa_los_glifos_de_salida_del_formato() {
+        let formato = FormatoTablero::nuevo('#', vec!['-', '0'], '#', '.');
+        let mut matriz = MatrizBuscaminas::con_formato(formato);
+        let bytes = "#-0".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
+            Ok(()) => {}
+            Err(error) => {
+                print!("Error al parsear archivo a tablero: {}", error);
+                return;
+            }
+        }
+        matriz.contar_bombas();
+
+        assert_eq!(matriz.to_ascii(), "#1.\n");
+    }
+
     #[test]
     fn test_contar_bombas_suma_adyacentes_horizontales() {
         let mut matriz = MatrizBuscaminas::new();
-        let bytes = [
-            INTERDOT_FIRST_BYTE,
-            ASTERISCO_BYTE,
-            INTERDOT_FIRST_BYTE,
-            b'\n',
-        ];
-        match matriz.popular_desde_bytes(&bytes) {
+        let bytes = "·*·\n".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
             Ok(()) => {}
             Err(error) => {
                 print!("Error al parsear archivo a tablero: {}", error);
@@ -303,15 +755,8 @@ mod tests {
     #[test]
     fn test_contar_bombas_suma_adyacentes_verticales() {
         let mut matriz = MatrizBuscaminas::new();
-        let bytes = [
-            INTERDOT_FIRST_BYTE,
-            b'\n',
-            ASTERISCO_BYTE,
-            b'\n',
-            INTERDOT_FIRST_BYTE,
-            b'\n',
-        ];
-        match matriz.popular_desde_bytes(&bytes) {
+        let bytes = "·\n*\n·\n".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
             Ok(()) => {}
             Err(error) => {
                 print!("Error al parsear archivo a tablero: {}", error);
@@ -326,18 +771,8 @@ mod tests {
     #[test]
     fn test_contar_bombas_suma_adyacentes_diagonales() {
         let mut matriz = MatrizBuscaminas::new();
-        let bytes = [
-            INTERDOT_FIRST_BYTE,
-            INTERDOT_FIRST_BYTE,
-            b'\n',
-            ASTERISCO_BYTE,
-            INTERDOT_FIRST_BYTE,
-            b'\n',
-            INTERDOT_FIRST_BYTE,
-            INTERDOT_FIRST_BYTE,
-            b'\n',
-        ];
-        match matriz.popular_desde_bytes(&bytes) {
+        let bytes = "··\n*·\n··\n".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
             Ok(()) => {}
             Err(error) => {
                 print!("Error al parsear archivo a tablero: {}", error);
@@ -352,8 +787,8 @@ mod tests {
     #[test]
     fn test_contar_bombas_suma_corectamente_dos_bombas() {
         let mut matriz = MatrizBuscaminas::new();
-        let bytes = [ASTERISCO_BYTE, INTERDOT_FIRST_BYTE, ASTERISCO_BYTE, b'\n'];
-        match matriz.popular_desde_bytes(&bytes) {
+        let bytes = "*·*\n".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
             Ok(()) => {}
             Err(error) => {
                 print!("Error al parsear archivo a tablero: {}", error);
@@ -367,16 +802,8 @@ mod tests {
     #[test]
     fn test_contar_bombas_suma_corectamente_tres_bombas() {
         let mut matriz = MatrizBuscaminas::new();
-        let bytes = [
-            ASTERISCO_BYTE,
-            INTERDOT_FIRST_BYTE,
-            ASTERISCO_BYTE,
-            b'\n',
-            INTERDOT_FIRST_BYTE,
-            ASTERISCO_BYTE,
-            INTERDOT_FIRST_BYTE,
-        ];
-        match matriz.popular_desde_bytes(&bytes) {
+        let bytes = "*·*\n·*·".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
             Ok(()) => {}
             Err(error) => {
                 print!("Error al parsear archivo a tablero: {}", error);
@@ -386,4 +813,155 @@ mod tests {
         matriz.contar_bombas();
         assert_eq!(matriz.valores[1], 3);
     }
+
+    #[test]
+    fn test_to_base64_y_from_base64_hacen_round_trip() {
+        let mut matriz = MatrizBuscaminas::new();
+        let bytes = "*·*\n·*·".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
+            Ok(()) => {}
+            Err(error) => {
+                print!("Error al parsear archivo a tablero: {}", error);
+                return;
+            }
+        }
+
+        let codificado = matriz.to_base64();
+        let mut decodificado = MatrizBuscaminas::new();
+        match decodificado.from_base64(&codificado) {
+            Ok(()) => {}
+            Err(error) => {
+                print!("Error al decodificar base64: {}", error);
+                return;
+            }
+        }
+
+        assert_eq!(decodificado.filas, matriz.filas);
+        assert_eq!(decodificado.columnas, matriz.columnas);
+        assert_eq!(decodificado.valores, matriz.valores);
+    }
+
+    #[test]
+    fn test_revelar_bomba_devuelve_boom() {
+        let mut matriz = MatrizBuscaminas::new();
+        let bytes = "*·".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
+            Ok(()) => {}
+            Err(error) => {
+                print!("Error al parsear archivo a tablero: {}", error);
+                return;
+            }
+        }
+        matriz.contar_bombas();
+
+        assert_eq!(matriz.revelar(0, 0), ResultadoJugada::Boom);
+    }
+
+    #[test]
+    fn test_revelar_celda_numerada_revela_solo_esa_celda() {
+        let mut matriz = MatrizBuscaminas::new();
+        let bytes = "·*·".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
+            Ok(()) => {}
+            Err(error) => {
+                print!("Error al parsear archivo a tablero: {}", error);
+                return;
+            }
+        }
+        matriz.contar_bombas();
+
+        assert_eq!(matriz.revelar(0, 0), ResultadoJugada::Revelada);
+        assert_eq!(matriz.estado[0], EstadoCelda::Revelada);
+        assert_eq!(matriz.estado[2], EstadoCelda::Oculta);
+    }
+
+    #[test]
+    fn test_revelar_celda_cero_hace_flood_fill() {
+        let mut matriz = MatrizBuscaminas::new();
+        let bytes = "···\n···\n*··".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
+            Ok(()) => {}
+            Err(error) => {
+                print!("Error al parsear archivo a tablero: {}", error);
+                return;
+            }
+        }
+        matriz.contar_bombas();
+
+        assert_eq!(matriz.revelar(0, 0), ResultadoJugada::Revelada);
+        for (indice, valor) in matriz.valores.iter().enumerate() {
+            if *valor == -1 {
+                assert_eq!(matriz.estado[indice], EstadoCelda::Oculta);
+            } else {
+                assert_eq!(matriz.estado[indice], EstadoCelda::Revelada);
+            }
+        }
+    }
+
+    #[test]
+    fn test_revelar_fuera_de_rango() {
+        let mut matriz = MatrizBuscaminas::new();
+        let bytes = "·".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
+            Ok(()) => {}
+            Err(error) => {
+                print!("Error al parsear archivo a tablero: {}", error);
+                return;
+            }
+        }
+        matriz.contar_bombas();
+
+        assert_eq!(matriz.revelar(5, 5), ResultadoJugada::FueraDeRango);
+    }
+
+    #[test]
+    fn test_alternar_bandera_marca_y_desmarca_celda_oculta() {
+        let mut matriz = MatrizBuscaminas::new();
+        let bytes = "·".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
+            Ok(()) => {}
+            Err(error) => {
+                print!("Error al parsear archivo a tablero: {}", error);
+                return;
+            }
+        }
+        matriz.contar_bombas();
+
+        assert!(matriz.alternar_bandera(0, 0));
+        assert_eq!(matriz.estado[0], EstadoCelda::Bandera);
+        assert!(matriz.alternar_bandera(0, 0));
+        assert_eq!(matriz.estado[0], EstadoCelda::Oculta);
+    }
+
+    #[test]
+    fn test_exportar_como_dot_marca_bombas_y_celdas_numeradas() {
+        let mut matriz = MatrizBuscaminas::new();
+        let bytes = "·*·".as_bytes();
+        match matriz.popular_desde_bytes(bytes) {
+            Ok(()) => {}
+            Err(error) => {
+                print!("Error al parsear archivo a tablero: {}", error);
+                return;
+            }
+        }
+        matriz.contar_bombas();
+
+        let dot = matriz.exportar_como_dot();
+        assert!(dot.starts_with("digraph Buscaminas {"));
+        assert!(dot.contains("c_0_0 [label=\"1\", fillcolor=white];"));
+        assert!(dot.contains("c_0_1 [label=\"*\", fillcolor=red];"));
+        assert!(dot.contains("c_0_2 [label=\"1\", fillcolor=white];"));
+        assert!(dot.contains("rank=same"));
+    }
+
+    #[test]
+    fn test_from_base64_rechaza_datos_insuficientes() {
+        let mut matriz = MatrizBuscaminas::new();
+        // Encabezado que declara un tablero de 10x10 sin ningún byte de datos.
+        let mut encabezado = Vec::new();
+        encabezado.extend_from_slice(&10u32.to_le_bytes());
+        encabezado.extend_from_slice(&10u32.to_le_bytes());
+        let codificado = MatrizBuscaminas::codificar_base64(&encabezado);
+        assert!(matriz.from_base64(&codificado).is_err());
+    }
 }